@@ -3,16 +3,14 @@ use std::hash::Hash;
 use cmv::Cmv;
 use divan::{bench, Bencher};
 use rand::rngs::SmallRng;
-use rand::SeedableRng;
 
 fn run_bench<T>(words: &[T], capacity: usize) -> u128
 where
     T: Eq + Hash,
 {
-    let mut rng = SmallRng::seed_from_u64(0x1234);
-    let mut cmv = Cmv::<&T>::with_capacity(capacity);
+    let mut cmv = Cmv::<&T>::seed_from_u64(capacity, 0x1234);
     for word in words {
-        cmv.insert(word, &mut rng);
+        cmv.insert(word);
     }
     cmv.count()
 }